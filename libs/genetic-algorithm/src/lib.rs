@@ -1,28 +1,130 @@
+mod chromosome;
+mod crossover;
+mod individual;
+mod mutation;
+mod selection;
+
+pub use self::{
+    chromosome::Chromosome,
+    crossover::{CrossoverMethod, UniformCrossover},
+    individual::Individual,
+    mutation::{GaussianMutation, MutationMethod},
+    selection::{RouletteWheelSelection, SelectionMethod},
+};
+
 use rand::RngCore;
 
-pub struct GeneticAlgorithm;
+pub struct GeneticAlgorithm<S> {
+    selection_method: S,
+}
 
-impl GeneticAlgorithm {
-    pub fn new() -> Self {
-        Self
+impl<S> GeneticAlgorithm<S>
+where
+    S: SelectionMethod,
+{
+    pub fn new(selection_method: S) -> Self {
+        Self { selection_method }
     }
 
-    pub fn evolve<I>(&self, population: &[I]) -> Vec<I> {
+    pub fn evolve<I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &[I],
+        crossover_method: &dyn CrossoverMethod,
+        mutation_method: &dyn MutationMethod,
+    ) -> Vec<I>
+    where
+        I: Individual,
+    {
         assert!(!population.is_empty());
 
         (0..population.len())
             .map(|_| {
-                // crossover
-                // selection
-                // mutation
-                todo!()
+                let parent_a = self.selection_method.select(rng, population).chromosome();
+                let parent_b = self.selection_method.select(rng, population).chromosome();
+
+                let mut child = crossover_method.crossover(rng, parent_a, parent_b);
+
+                mutation_method.mutate(rng, &mut child);
+
+                I::create(child)
             })
             .collect()
     }
 }
 
-pub trait SelectionMethod {
-    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
-    where
-        I: Individual;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum TestIndividual {
+        WithChromosome { chromosome: Chromosome },
+    }
+
+    impl TestIndividual {
+        fn new(genes: &[f32]) -> Self {
+            Self::WithChromosome {
+                chromosome: genes.iter().copied().collect(),
+            }
+        }
+    }
+
+    impl Individual for TestIndividual {
+        fn create(chromosome: Chromosome) -> Self {
+            Self::WithChromosome { chromosome }
+        }
+
+        fn chromosome(&self) -> &Chromosome {
+            match self {
+                Self::WithChromosome { chromosome } => chromosome,
+            }
+        }
+
+        fn fitness(&self) -> f32 {
+            match self {
+                Self::WithChromosome { chromosome } => chromosome.iter().sum(),
+            }
+        }
+    }
+
+    struct TestCrossover;
+
+    impl CrossoverMethod for TestCrossover {
+        fn crossover(
+            &self,
+            _rng: &mut dyn RngCore,
+            parent_a: &Chromosome,
+            _parent_b: &Chromosome,
+        ) -> Chromosome {
+            parent_a.clone()
+        }
+    }
+
+    struct TestMutation;
+
+    impl MutationMethod for TestMutation {
+        fn mutate(&self, _rng: &mut dyn RngCore, _child: &mut Chromosome) {
+            // no-op: keeps the evolve() test focused on selection/crossover wiring
+        }
+    }
+
+    #[test]
+    fn evolve_preserves_population_size() {
+        let ga = GeneticAlgorithm::new(RouletteWheelSelection::new());
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let population = vec![
+            TestIndividual::new(&[1.0]),
+            TestIndividual::new(&[2.0]),
+            TestIndividual::new(&[3.0]),
+            TestIndividual::new(&[4.0]),
+        ];
+
+        let new_population = ga.evolve(&mut rng, &population, &TestCrossover, &TestMutation);
+
+        assert_eq!(new_population.len(), population.len());
+    }
 }
@@ -0,0 +1,105 @@
+use crate::individual::Individual;
+use rand::{Rng, RngCore};
+
+pub trait SelectionMethod {
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: Individual;
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct RouletteWheelSelection;
+
+impl RouletteWheelSelection {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl SelectionMethod for RouletteWheelSelection {
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        let total_fitness: f32 = population
+            .iter()
+            .map(|individual| individual.fitness())
+            .sum();
+
+        assert!(total_fitness > 0.0);
+
+        let mut choice = rng.gen_range(0.0..total_fitness);
+
+        population
+            .iter()
+            .find(|individual| {
+                choice -= individual.fitness();
+                choice <= 0.0
+            })
+            .expect("roulette wheel selected no individual")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::BTreeMap;
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum TestIndividual {
+        WithFitness { fitness: f32 },
+    }
+
+    impl TestIndividual {
+        pub fn new(fitness: f32) -> Self {
+            Self::WithFitness { fitness }
+        }
+    }
+
+    impl Individual for TestIndividual {
+        fn create(_chromosome: crate::chromosome::Chromosome) -> Self {
+            unreachable!()
+        }
+
+        fn chromosome(&self) -> &crate::chromosome::Chromosome {
+            unreachable!()
+        }
+
+        fn fitness(&self) -> f32 {
+            match self {
+                Self::WithFitness { fitness } => *fitness,
+            }
+        }
+    }
+
+    #[test]
+    fn test() {
+        let method = RouletteWheelSelection::new();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let population = vec![
+            TestIndividual::new(2.0),
+            TestIndividual::new(1.0),
+            TestIndividual::new(4.0),
+            TestIndividual::new(3.0),
+        ];
+
+        let mut histogram = BTreeMap::new();
+
+        for _ in 0..1000 {
+            let fitness = method.select(&mut rng, &population).fitness() as i32;
+            *histogram.entry(fitness).or_insert(0) += 1;
+        }
+
+        // Every individual must've been picked at least once, and picks
+        // should roughly track fitness: the fittest individual is selected
+        // more often than the least fit one.
+        assert_eq!(
+            histogram.keys().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+        assert!(histogram[&4] > histogram[&1]);
+    }
+}
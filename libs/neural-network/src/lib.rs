@@ -1,13 +1,20 @@
 use rand::{Rng, RngCore};
+use rand_distr::StandardNormal;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
 
+#[derive(Serialize, Deserialize)]
 pub struct Network {
     layers: Vec<Layer>,
 }
 
+#[derive(Serialize, Deserialize)]
 struct Layer {
     neurons: Vec<Neuron>,
+    activation: Activation,
 }
 
+#[derive(Serialize, Deserialize)]
 struct Neuron {
     bias: f32,
     weights: Vec<f32>,
@@ -15,6 +22,70 @@ struct Neuron {
 
 pub struct LayerTopology {
     pub neurons: usize,
+    pub activation: Activation,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Activation {
+    Relu,
+    LeakyRelu { slope: f32 },
+    Sigmoid,
+    Tanh,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Init {
+    Uniform,
+    He,
+}
+
+impl Activation {
+    pub fn apply(self, x: f32) -> f32 {
+        match self {
+            Self::Relu => x.max(0.0),
+            Self::LeakyRelu { slope } => {
+                if x > 0.0 {
+                    x
+                } else {
+                    slope * x
+                }
+            }
+            Self::Sigmoid => 1.0 / (1.0 + (-x).exp()),
+            Self::Tanh => x.tanh(),
+        }
+    }
+
+    /// Derivative of `apply`, evaluated at the pre-activation value `x`.
+    ///
+    /// `Relu`'s derivative is technically `0.0` for `x <= 0.0`, but that
+    /// leaves a unit dead (and its weights unreachable by gradient descent)
+    /// the moment it ever fires negative, so we nudge it with a small leaky
+    /// slope instead.
+    pub fn derivative(self, x: f32) -> f32 {
+        const LEAK: f32 = 0.01;
+
+        match self {
+            Self::Relu => {
+                if x > 0.0 {
+                    1.0
+                } else {
+                    LEAK
+                }
+            }
+            Self::LeakyRelu { slope } => {
+                if x > 0.0 {
+                    1.0
+                } else {
+                    slope
+                }
+            }
+            Self::Sigmoid => {
+                let s = Self::Sigmoid.apply(x);
+                s * (1.0 - s)
+            }
+            Self::Tanh => 1.0 - x.tanh().powi(2),
+        }
+    }
 }
 
 impl Network {
@@ -24,37 +95,218 @@ impl Network {
             .fold(inputs, |inputs, layer| layer.propagate(inputs))
     }
 
-    pub fn random(rng: &mut dyn RngCore, layers: &[LayerTopology]) -> Self {
+    pub fn random(rng: &mut dyn RngCore, layers: &[LayerTopology], init: Init) -> Self {
         assert!(layers.len() > 1);
 
         let layers = layers
             .windows(2)
-            .map(|layers| Layer::random(rng, layers[0].neurons, layers[1].neurons))
+            .map(|layers| {
+                Layer::random(
+                    rng,
+                    layers[0].neurons,
+                    layers[1].neurons,
+                    layers[1].activation,
+                    init,
+                )
+            })
             .collect();
 
         Self { layers }
     }
+
+    pub fn weights(&self) -> impl Iterator<Item = f32> + '_ {
+        self.layers
+            .iter()
+            .flat_map(|layer| layer.neurons.iter())
+            .flat_map(|neuron| std::iter::once(neuron.bias).chain(neuron.weights.iter().copied()))
+    }
+
+    pub fn from_weights(layers: &[LayerTopology], weights: impl IntoIterator<Item = f32>) -> Self {
+        assert!(layers.len() > 1);
+
+        let mut weights = weights.into_iter();
+
+        let layers = layers
+            .windows(2)
+            .map(|layers| {
+                Layer::from_weights(
+                    layers[0].neurons,
+                    layers[1].neurons,
+                    layers[1].activation,
+                    &mut weights,
+                )
+            })
+            .collect();
+
+        Self { layers }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("failed to serialize network")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_json())
+    }
+
+    pub fn load_from_file(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+
+        Self::from_json(&json)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+
+    /// Trains the network in place via stochastic gradient descent over
+    /// mean-squared-error loss, running `epochs` passes over `samples`.
+    pub fn train(&mut self, samples: &[(Vec<f32>, Vec<f32>)], lr: f32, epochs: usize) {
+        for _ in 0..epochs {
+            for (inputs, targets) in samples {
+                self.train_one(inputs, targets, lr);
+            }
+        }
+    }
+
+    fn train_one(&mut self, inputs: &[f32], targets: &[f32], lr: f32) {
+        let mut layer_inputs = inputs.to_vec();
+
+        // Forward pass: cache each layer's inputs, pre-activations and
+        // outputs, since the backward pass needs all three.
+        let cache: Vec<_> = self
+            .layers
+            .iter()
+            .map(|layer| {
+                let (pre_activations, outputs) = layer.propagate_cached(&layer_inputs);
+                let layer_inputs_snapshot = std::mem::replace(&mut layer_inputs, outputs.clone());
+
+                (layer_inputs_snapshot, pre_activations, outputs)
+            })
+            .collect();
+
+        let mut next_deltas: Option<Vec<f32>> = None;
+        let mut next_weights: Option<Vec<Vec<f32>>> = None;
+
+        for layer_idx in (0..self.layers.len()).rev() {
+            let is_output_layer = layer_idx == self.layers.len() - 1;
+            let (layer_inputs, pre_activations, outputs) = &cache[layer_idx];
+            let layer = &mut self.layers[layer_idx];
+
+            let deltas: Vec<f32> = (0..layer.neurons.len())
+                .map(|neuron_idx| {
+                    let derivative = layer.activation.derivative(pre_activations[neuron_idx]);
+
+                    if is_output_layer {
+                        (outputs[neuron_idx] - targets[neuron_idx]) * derivative
+                    } else {
+                        let next_deltas = next_deltas
+                            .as_ref()
+                            .expect("hidden layer without a downstream layer");
+                        let next_weights = next_weights
+                            .as_ref()
+                            .expect("hidden layer without a downstream layer");
+
+                        let propagated: f32 = next_deltas
+                            .iter()
+                            .zip(next_weights)
+                            .map(|(delta, weights)| delta * weights[neuron_idx])
+                            .sum();
+
+                        propagated * derivative
+                    }
+                })
+                .collect();
+
+            // Snapshot this layer's weights before updating them, so the
+            // shallower layer's backward pass sees the weights that were
+            // actually used during the forward pass.
+            next_weights = Some(
+                layer
+                    .neurons
+                    .iter()
+                    .map(|neuron| neuron.weights.clone())
+                    .collect(),
+            );
+            next_deltas = Some(deltas.clone());
+
+            for (neuron, &delta) in layer.neurons.iter_mut().zip(&deltas) {
+                for (weight, &input) in neuron.weights.iter_mut().zip(layer_inputs) {
+                    *weight -= lr * delta * input;
+                }
+
+                neuron.bias -= lr * delta;
+            }
+        }
+    }
 }
 
 impl Layer {
     fn propagate(&self, inputs: Vec<f32>) -> Vec<f32> {
         self.neurons
             .iter()
-            .map(|neuron| neuron.propagate(&inputs))
+            .map(|neuron| neuron.propagate(&inputs, self.activation))
             .collect()
     }
 
-    pub fn random(rng: &mut dyn RngCore, input_neurons: usize, output_neurons: usize) -> Self {
+    /// Like `propagate`, but also returns each neuron's pre-activation
+    /// value (needed by `Network::train` to compute derivatives).
+    fn propagate_cached(&self, inputs: &[f32]) -> (Vec<f32>, Vec<f32>) {
+        let pre_activations: Vec<f32> = self
+            .neurons
+            .iter()
+            .map(|neuron| neuron.pre_activation(inputs))
+            .collect();
+
+        let outputs = pre_activations
+            .iter()
+            .map(|&pre_activation| self.activation.apply(pre_activation))
+            .collect();
+
+        (pre_activations, outputs)
+    }
+
+    pub fn random(
+        rng: &mut dyn RngCore,
+        input_neurons: usize,
+        output_neurons: usize,
+        activation: Activation,
+        init: Init,
+    ) -> Self {
+        let neurons = (0..output_neurons)
+            .map(|_| Neuron::random(rng, input_neurons, init))
+            .collect();
+
+        Self {
+            neurons,
+            activation,
+        }
+    }
+
+    fn from_weights(
+        input_neurons: usize,
+        output_neurons: usize,
+        activation: Activation,
+        weights: &mut dyn Iterator<Item = f32>,
+    ) -> Self {
         let neurons = (0..output_neurons)
-            .map(|_| Neuron::random(rng, input_neurons))
+            .map(|_| Neuron::from_weights(input_neurons, weights))
             .collect();
 
-        Self { neurons }
+        Self {
+            neurons,
+            activation,
+        }
     }
 }
 
 impl Neuron {
-    fn propagate(&self, inputs: &[f32]) -> f32 {
+    fn propagate(&self, inputs: &[f32], activation: Activation) -> f32 {
+        activation.apply(self.pre_activation(inputs))
+    }
+
+    fn pre_activation(&self, inputs: &[f32]) -> f32 {
         assert_eq!(inputs.len(), self.weights.len());
 
         let output = inputs
@@ -63,14 +315,39 @@ impl Neuron {
             .map(|(input, weight)| input * weight)
             .sum::<f32>();
 
-        (self.bias + output).max(0.0)
+        self.bias + output
     }
 
-    pub fn random(rng: &mut dyn rand::RngCore, output_size: usize) -> Self {
-        let bias = rng.gen_range(-1.0..=1.0);
+    pub fn random(rng: &mut dyn rand::RngCore, output_size: usize, init: Init) -> Self {
+        match init {
+            Init::Uniform => {
+                let bias = rng.gen_range(-1.0..=1.0);
+
+                let weights = (0..output_size)
+                    .map(|_| rng.gen_range(-1.0..=1.0))
+                    .collect();
+
+                Self { bias, weights }
+            }
+
+            Init::He => {
+                let scale = (2.0 / output_size as f32).sqrt();
+                let bias = 0.0;
 
-        let weights = (0..output_size)
-            .map(|_| rng.gen_range(-1.0..=1.0))
+                let weights = (0..output_size)
+                    .map(|_| rng.sample::<f32, _>(StandardNormal) * scale)
+                    .collect();
+
+                Self { bias, weights }
+            }
+        }
+    }
+
+    fn from_weights(input_size: usize, weights: &mut dyn Iterator<Item = f32>) -> Self {
+        let bias = weights.next().expect("not enough weights");
+
+        let weights = (0..input_size)
+            .map(|_| weights.next().expect("not enough weights"))
             .collect();
 
         Self { bias, weights }
@@ -90,7 +367,7 @@ mod tests {
         #[test]
         fn test() {
             let mut rng = ChaCha8Rng::from_seed(Default::default());
-            let neuron = Neuron::random(&mut rng, 4);
+            let neuron = Neuron::random(&mut rng, 4, Init::Uniform);
 
             assert_relative_eq!(neuron.bias, -0.6255188);
             assert_relative_eq!(
@@ -100,6 +377,29 @@ mod tests {
         }
     }
 
+    mod random_he {
+        use super::*;
+        use approx::assert_relative_eq;
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha8Rng;
+
+        #[test]
+        fn test() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let neuron = Neuron::random(&mut rng, 4, Init::He);
+
+            // He initialization leaves the bias at zero...
+            assert_relative_eq!(neuron.bias, 0.0);
+
+            // ...and draws weights from a standard normal scaled by
+            // sqrt(2 / fan_in).
+            assert_relative_eq!(
+                neuron.weights.as_slice(),
+                &[0.974179, 0.28662348, -0.84588987, -1.3682848].as_ref()
+            );
+        }
+    }
+
     mod propagate {
         use super::*;
 
@@ -111,11 +411,11 @@ mod tests {
             };
 
             // Ensures `.max()` (our ReLU) works:
-            approx::assert_relative_eq!(neuron.propagate(&[-10.0, -10.0]), 0.0,);
+            approx::assert_relative_eq!(neuron.propagate(&[-10.0, -10.0], Activation::Relu), 0.0,);
 
             // `0.5` and `1.0` chose by a fair dice roll:
             approx::assert_relative_eq!(
-                neuron.propagate(&[0.5, 1.0]),
+                neuron.propagate(&[0.5, 1.0], Activation::Relu),
                 (-0.3 * 0.5) + (0.8 * 1.0) + 0.5,
             );
 
@@ -124,6 +424,35 @@ mod tests {
         }
     }
 
+    mod activation {
+        use super::*;
+        use approx::assert_relative_eq;
+
+        #[test]
+        fn relu() {
+            assert_relative_eq!(Activation::Relu.apply(-1.0), 0.0);
+            assert_relative_eq!(Activation::Relu.apply(1.0), 1.0);
+        }
+
+        #[test]
+        fn leaky_relu() {
+            let activation = Activation::LeakyRelu { slope: 0.1 };
+
+            assert_relative_eq!(activation.apply(-1.0), -0.1);
+            assert_relative_eq!(activation.apply(1.0), 1.0);
+        }
+
+        #[test]
+        fn sigmoid() {
+            assert_relative_eq!(Activation::Sigmoid.apply(0.0), 0.5);
+        }
+
+        #[test]
+        fn tanh() {
+            assert_relative_eq!(Activation::Tanh.apply(0.0), 0.0);
+        }
+    }
+
     mod layer {
         use super::*;
 
@@ -144,6 +473,7 @@ mod tests {
 
                 let layer = Layer {
                     neurons: vec![neuron1, neuron2],
+                    activation: Activation::Relu,
                 };
 
                 let result = layer.propagate(vec![-10.0, -10.0]);
@@ -170,7 +500,7 @@ mod tests {
             #[test]
             fn test() {
                 let mut rng = ChaCha8Rng::from_seed(Default::default());
-                let layer = Layer::random(&mut rng, 2, 2);
+                let layer = Layer::random(&mut rng, 2, 2, Activation::Relu, Init::Uniform);
 
                 // Neuron biases
                 assert_relative_eq!(layer.neurons[0].bias, -0.6255188);
@@ -202,10 +532,19 @@ mod tests {
             fn test() {
                 let mut rng = ChaCha8Rng::from_seed(Default::default());
 
-                let layer1 = LayerTopology { neurons: 3 };
-                let layer2 = LayerTopology { neurons: 2 };
-                let layer3 = LayerTopology { neurons: 1 };
-                let network = Network::random(&mut rng, &[layer1, layer2, layer3]);
+                let layer1 = LayerTopology {
+                    neurons: 3,
+                    activation: Activation::Relu,
+                };
+                let layer2 = LayerTopology {
+                    neurons: 2,
+                    activation: Activation::Relu,
+                };
+                let layer3 = LayerTopology {
+                    neurons: 1,
+                    activation: Activation::Relu,
+                };
+                let network = Network::random(&mut rng, &[layer1, layer2, layer3], Init::Uniform);
                 assert_eq!(network.layers.len(), 2);
 
                 // Testing layer 1
@@ -235,6 +574,178 @@ mod tests {
             }
         }
 
+        mod weights {
+            use super::*;
+            use approx::assert_relative_eq;
+
+            #[test]
+            fn test() {
+                let network = Network {
+                    layers: vec![
+                        Layer {
+                            neurons: vec![Neuron {
+                                bias: 0.1,
+                                weights: vec![0.2, 0.3, 0.4],
+                            }],
+                            activation: Activation::Relu,
+                        },
+                        Layer {
+                            neurons: vec![Neuron {
+                                bias: 0.5,
+                                weights: vec![0.6],
+                            }],
+                            activation: Activation::Relu,
+                        },
+                    ],
+                };
+
+                let weights: Vec<_> = network.weights().collect();
+
+                assert_relative_eq!(weights.as_slice(), &[0.1, 0.2, 0.3, 0.4, 0.5, 0.6].as_ref());
+            }
+        }
+
+        mod from_weights {
+            use super::*;
+            use approx::assert_relative_eq;
+
+            #[test]
+            fn test() {
+                // 1 output neuron with 4 inputs consumes exactly 1 bias + 4
+                // weights = 5 genes, matching `weights` below.
+                let layers = &[
+                    LayerTopology {
+                        neurons: 4,
+                        activation: Activation::Relu,
+                    },
+                    LayerTopology {
+                        neurons: 1,
+                        activation: Activation::Relu,
+                    },
+                ];
+
+                let weights = vec![0.1, 0.2, 0.3, 0.4, 0.5];
+                let network = Network::from_weights(layers, weights.clone());
+                let actual: Vec<_> = network.weights().collect();
+
+                assert_relative_eq!(actual.as_slice(), weights.as_slice());
+            }
+
+            #[test]
+            #[should_panic(expected = "not enough weights")]
+            fn panics_when_not_enough_weights_are_supplied() {
+                let layers = &[
+                    LayerTopology {
+                        neurons: 3,
+                        activation: Activation::Relu,
+                    },
+                    LayerTopology {
+                        neurons: 1,
+                        activation: Activation::Relu,
+                    },
+                ];
+
+                Network::from_weights(layers, vec![0.1, 0.2]);
+            }
+        }
+
+        mod json {
+            use super::*;
+            use approx::assert_relative_eq;
+            use rand::SeedableRng;
+            use rand_chacha::ChaCha8Rng;
+
+            #[test]
+            fn round_trips_topology_and_weights() {
+                let layers = &[
+                    LayerTopology {
+                        neurons: 3,
+                        activation: Activation::Sigmoid,
+                    },
+                    LayerTopology {
+                        neurons: 2,
+                        activation: Activation::Tanh,
+                    },
+                ];
+
+                let mut rng = ChaCha8Rng::from_seed(Default::default());
+                let network = Network::random(&mut rng, layers, Init::Uniform);
+
+                let json = network.to_json();
+                let restored = Network::from_json(&json).expect("valid JSON");
+
+                assert_relative_eq!(
+                    network.weights().collect::<Vec<_>>().as_slice(),
+                    restored.weights().collect::<Vec<_>>().as_slice()
+                );
+
+                let inputs = vec![0.1, 0.2, 0.3];
+                assert_relative_eq!(
+                    network.propagate(inputs.clone()).as_slice(),
+                    restored.propagate(inputs).as_slice()
+                );
+            }
+        }
+
+        mod train {
+            use super::*;
+            use rand::SeedableRng;
+
+            #[test]
+            fn reduces_mean_squared_error_on_the_training_set() {
+                let layers = &[
+                    LayerTopology {
+                        neurons: 2,
+                        activation: Activation::Relu,
+                    },
+                    LayerTopology {
+                        neurons: 4,
+                        activation: Activation::Sigmoid,
+                    },
+                    LayerTopology {
+                        neurons: 1,
+                        activation: Activation::Sigmoid,
+                    },
+                ];
+
+                let mut rng = rand_chacha::ChaCha8Rng::from_seed(Default::default());
+                let mut network = Network::random(&mut rng, layers, Init::Uniform);
+
+                // XOR: not linearly separable, so fitting it needs the hidden
+                // layer; this test only checks that the loss goes down.
+                let samples = vec![
+                    (vec![0.0, 0.0], vec![0.0]),
+                    (vec![0.0, 1.0], vec![1.0]),
+                    (vec![1.0, 0.0], vec![1.0]),
+                    (vec![1.0, 1.0], vec![0.0]),
+                ];
+
+                let mse = |network: &Network| -> f32 {
+                    samples
+                        .iter()
+                        .map(|(inputs, targets)| {
+                            let output = network.propagate(inputs.clone());
+
+                            output
+                                .iter()
+                                .zip(targets)
+                                .map(|(o, t)| (o - t).powi(2))
+                                .sum::<f32>()
+                        })
+                        .sum::<f32>()
+                        / samples.len() as f32
+                };
+
+                let loss_before = mse(&network);
+
+                network.train(&samples, 0.5, 2000);
+
+                let loss_after = mse(&network);
+
+                assert!(loss_after < loss_before);
+            }
+        }
+
         mod propagate {
             use super::*;
             use approx::assert_relative_eq;
@@ -273,14 +784,17 @@ mod tests {
 
                 let layer1 = Layer {
                     neurons: vec![neuron1, neuron2, neuron3],
+                    activation: Activation::Relu,
                 };
 
                 let layer2 = Layer {
                     neurons: vec![neuron4, neuron5],
+                    activation: Activation::Relu,
                 };
 
                 let layer3 = Layer {
                     neurons: vec![neuron6],
+                    activation: Activation::Relu,
                 };
 
                 let network = Network {